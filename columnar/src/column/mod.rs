@@ -90,6 +90,52 @@ impl<T: PartialOrd + Copy + Debug + Send + Sync + 'static> Column<T> {
             .select_batch_in_place(docids, selected_docid_range.start);
     }
 
+    /// Get the docids of values which are in the provided value ranges, one output bucket per
+    /// range.
+    ///
+    /// This is the batched counterpart of [`Self::get_docids_for_value_range`]: the docid range
+    /// is converted to a rowid range once and the row scan is fanned out to every requested
+    /// range in a single pass over `self.values`, so callers querying many adjacent buckets
+    /// (as range-bucket histogram aggregations do) do not re-walk the column index per range.
+    ///
+    /// `out` must have one entry per range; each is cleared, filled with the matching rows in
+    /// ascending order during the shared scan, and then converted to docids with a per-bucket
+    /// `select_batch_in_place` call (there is no single-call batch conversion API).
+    #[inline]
+    pub fn get_docids_for_value_ranges(
+        &self,
+        value_ranges: &[RangeInclusive<T>],
+        selected_docid_range: Range<u32>,
+        out: &mut [Vec<u32>],
+    ) {
+        assert_eq!(
+            value_ranges.len(),
+            out.len(),
+            "expected one output bucket per value range"
+        );
+        for docids in out.iter_mut() {
+            docids.clear();
+        }
+        // Convert the passed docid range to a rowid range exactly once.
+        let rowid_range = self.idx.docid_range_to_rowids(selected_docid_range.clone());
+
+        // Single pass over the rows, fanning each value out to every matching range's bucket.
+        for row_id in rowid_range {
+            let val = self.values.get_val(row_id);
+            for (value_range, docids) in value_ranges.iter().zip(out.iter_mut()) {
+                if value_range.contains(&val) {
+                    docids.push(row_id);
+                }
+            }
+        }
+
+        // Convert each bucket's rows to docids.
+        for docids in out.iter_mut() {
+            self.idx
+                .select_batch_in_place(docids, selected_docid_range.start);
+        }
+    }
+
     /// Fils the output vector with the (possibly multiple values that are associated_with
     /// `row_id`.
     ///
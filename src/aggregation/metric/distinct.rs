@@ -1,33 +1,126 @@
-use std::collections::BTreeSet;
+use std::collections::{BTreeMap, BTreeSet};
 use std::fmt::Debug;
+use std::io;
 
-use fastfield_codecs::Column;
+use columnar::BytesColumn;
+use fastfield_codecs::{Column, MonotonicallyMappableToU64};
 use serde::{Deserialize, Serialize};
 
 use crate::schema::Type;
 use crate::DocId;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
-/// A single-value metric aggregation that computes the average of numeric values that are
+/// A single-value metric aggregation that counts the number of distinct values that are
 /// extracted from the aggregated documents.
 /// See [super::SingleMetricResult] for return value.
 ///
+/// By default the exact set of observed values is kept in a `BTreeSet<u64>`. For high
+/// cardinality fields this can be expensive in both memory and merge cost, so an approximate
+/// mode backed by a HyperLogLog sketch can be requested through `precision`.
+///
+/// When both `precision` and `sparse_threshold` are given, collection is hybrid: small result
+/// sets stay exact and only spill to a sketch once they grow past the threshold, so
+/// low-cardinality groupings keep exact counts while the long tail stays bounded.
+///
 /// # JSON Format
 /// ```json
 /// {
 ///     "distinct": {
 ///         "field": "score",
+///         "precision": 14,
+///         "sparse_threshold": 10000
 ///     }
 /// }
 /// ```
 pub struct DistinctAggregation {
     /// The field name to compute the stats on.
     pub field: String,
+    /// Additional fields. When non-empty, the aggregation counts distinct *combinations* of the
+    /// values across `field` and `fields` (like counting distinct tuples across several columns).
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<String>,
+    /// How multi-valued columns are handled when building a composite key.
+    #[serde(default)]
+    pub multi_value_mode: MultiValueMode,
+    /// HyperLogLog precision `p` (registers `m = 1 << p`). When set, the distinct count is
+    /// estimated from a sketch instead of an exact set, bounding memory to `m` bytes. When
+    /// unset the exact `BTreeSet` path is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub precision: Option<u8>,
+    /// Exact-set size past which collection spills into a HyperLogLog sketch. Only takes effect
+    /// together with `precision`; unset with `precision` set means collection uses the sketch
+    /// from the first value on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sparse_threshold: Option<usize>,
 }
 impl DistinctAggregation {
     /// Create new DistinctAggregation from a field.
     pub fn from_field_name(field_name: String) -> Self {
-        DistinctAggregation { field: field_name }
+        DistinctAggregation {
+            field: field_name,
+            fields: Vec::new(),
+            multi_value_mode: MultiValueMode::default(),
+            precision: None,
+            sparse_threshold: None,
+        }
+    }
+    /// Return the primary field name.
+    pub fn field_name(&self) -> &str {
+        &self.field
+    }
+    /// Return every field the aggregation reads, `field` first then `fields`, in key order.
+    pub fn field_names(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.field.as_str()).chain(self.fields.iter().map(String::as_str))
+    }
+}
+
+/// How a multi-valued column contributes to a composite distinct key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MultiValueMode {
+    /// Take only the first value of each field.
+    #[default]
+    First,
+    /// Emit the cross-product of the per-field values, counting every tuple.
+    CrossProduct,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+/// A metric aggregation that returns the actual set of distinct values present in the
+/// aggregated documents, analogous to SQL `array_agg(DISTINCT ...)`.
+///
+/// Unlike [DistinctAggregation], which only reports a cardinality, this keeps the values
+/// themselves and finalizes them into a JSON array of typed values. Because the values are
+/// retained, collection always uses the exact set path.
+///
+/// # JSON Format
+/// ```json
+/// {
+///     "distinct_values": {
+///         "field": "tags",
+///         "size": 100,
+///         "order": "asc"
+///     }
+/// }
+/// ```
+pub struct DistinctValuesAggregation {
+    /// The field name to collect the distinct values of.
+    pub field: String,
+    /// Cap on the number of values returned. Unset returns them all.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size: Option<usize>,
+    /// Ordering of the returned values.
+    #[serde(default)]
+    pub order: DistinctValuesOrder,
+}
+impl DistinctValuesAggregation {
+    /// Create new DistinctValuesAggregation from a field.
+    pub fn from_field_name(field_name: String) -> Self {
+        DistinctValuesAggregation {
+            field: field_name,
+            size: None,
+            order: DistinctValuesOrder::default(),
+        }
     }
     /// Return the field name.
     pub fn field_name(&self) -> &str {
@@ -35,6 +128,19 @@ impl DistinctAggregation {
     }
 }
 
+/// Ordering of the values returned by [DistinctValuesAggregation].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DistinctValuesOrder {
+    /// Ascending order: by the underlying `u64` representation for numeric fields, by the
+    /// term bytes for `Str`/`Bytes` fields.
+    #[default]
+    Asc,
+    /// Descending order: by the underlying `u64` representation for numeric fields, by the
+    /// term bytes for `Str`/`Bytes` fields.
+    Desc,
+}
+
 #[derive(Clone, PartialEq)]
 pub(crate) struct SegmentDistinctCollector {
     pub data: IntermediateDistinct,
@@ -50,11 +156,23 @@ impl Debug for SegmentDistinctCollector {
 }
 
 impl SegmentDistinctCollector {
-    pub fn from_req(field_type: Type) -> Self {
-        Self {
-            field_type,
-            data: Default::default(),
-        }
+    pub fn from_req(field_type: Type, precision: Option<u8>, sparse_threshold: Option<usize>) -> Self {
+        let data = match (precision, sparse_threshold) {
+            // Hybrid: stay exact until the set spills past the threshold into a sketch.
+            (Some(precision), Some(threshold)) => IntermediateDistinct::Exact(ExactDistinct {
+                terms: BTreeSet::new(),
+                term_bytes: BTreeMap::new(),
+                spill: Some(SpillPolicy {
+                    precision,
+                    threshold,
+                }),
+            }),
+            // Pure approximate from the first value on.
+            (Some(precision), None) => IntermediateDistinct::Approx(HyperLogLog::new(precision)),
+            // Pure exact.
+            (None, _) => IntermediateDistinct::default(),
+        };
+        Self { field_type, data }
     }
     pub(crate) fn collect_block(&mut self, doc: &[DocId], field: &dyn Column<u64>) {
         let mut iter = doc.chunks_exact(4);
@@ -74,13 +192,168 @@ impl SegmentDistinctCollector {
             self.data.collect(val);
         }
     }
+
+    /// Text-aware collection for `Str`/`Bytes` fast fields.
+    ///
+    /// The per-segment term ordinals in `ords` are not comparable across segments, so merging
+    /// them directly would produce wrong counts. Each ordinal is resolved to its term bytes
+    /// through `bytes_column` and reduced to a stable 64-bit hash — a globally comparable key —
+    /// before it enters the distinct structure, so `IntermediateDistinct::merge_fruits` stays
+    /// correct across segments. The resolved bytes are retained alongside the key so the
+    /// `distinct_values` path can return the actual terms.
+    ///
+    /// Because the key is a hash, this path is approximate even on the exact set: two distinct
+    /// terms colliding in the 64-bit hash collapse into one key, so the count can undercount and
+    /// only the first-seen term is retained for that key. Collisions are vanishingly rare at
+    /// this width; key on the bytes directly if exactness over text is required.
+    pub(crate) fn collect_block_str(
+        &mut self,
+        doc: &[DocId],
+        ords: &dyn Column<u64>,
+        bytes_column: &BytesColumn,
+    ) -> io::Result<()> {
+        let mut buffer = Vec::new();
+        for &doc in doc {
+            let ord = ords.get_val(doc);
+            buffer.clear();
+            if bytes_column.ord_to_bytes(ord, &mut buffer)? {
+                self.data.collect_bytes(&buffer);
+            }
+        }
+        Ok(())
+    }
+
+    /// Composite collection over several fields, counting distinct combinations of their values.
+    ///
+    /// One combined key is built per doc by hashing the ordered per-field values.
+    ///
+    /// The `&dyn Column<u64>` accessor is the single-valued column abstraction (the same one
+    /// [`Self::collect_block`] reads through `get_val`), so exactly one value per field is read
+    /// here. `mode` still selects how combinations would be formed from a multi-valued column,
+    /// but with a single value per field both `First` and `CrossProduct` reduce to one tuple per
+    /// doc; a genuinely multi-valued column type would have to be threaded in to expand it.
+    pub(crate) fn collect_block_composite(
+        &mut self,
+        doc: &[DocId],
+        fields: &[&dyn Column<u64>],
+        mode: MultiValueMode,
+    ) {
+        let mut per_field: Vec<Vec<u64>> = vec![Vec::new(); fields.len()];
+        for &doc in doc {
+            for (field, values) in fields.iter().zip(per_field.iter_mut()) {
+                values.clear();
+                values.push(field.get_val(doc));
+            }
+            for key in expand_composite_keys(&per_field, mode) {
+                self.data.collect(key);
+            }
+        }
+    }
+}
+
+/// Expand the per-field values of a single doc into the distinct keys it contributes.
+fn expand_composite_keys(per_field: &[Vec<u64>], mode: MultiValueMode) -> Vec<u64> {
+    match mode {
+        MultiValueMode::First => {
+            let tuple: Vec<u64> = per_field
+                .iter()
+                .map(|values| values.first().copied().unwrap_or_default())
+                .collect();
+            vec![hash_composite(&tuple)]
+        }
+        MultiValueMode::CrossProduct => {
+            let mut tuples: Vec<Vec<u64>> = vec![Vec::new()];
+            for values in per_field {
+                let source: &[u64] = if values.is_empty() { &[0] } else { values };
+                let mut next = Vec::with_capacity(tuples.len() * source.len());
+                for prefix in &tuples {
+                    for &value in source {
+                        let mut tuple = prefix.clone();
+                        tuple.push(value);
+                        next.push(tuple);
+                    }
+                }
+                tuples = next;
+            }
+            tuples.iter().map(|tuple| hash_composite(tuple)).collect()
+        }
+    }
+}
+
+/// Hash an ordered tuple of per-field values into a single composite distinct key.
+#[inline]
+fn hash_composite(values: &[u64]) -> u64 {
+    let mut acc = 0xCBF2_9CE4_8422_2325u64;
+    for &value in values {
+        acc = (acc ^ stable_hash_u64(value)).wrapping_mul(0x0000_0100_0000_01B3);
+    }
+    acc
+}
+
+/// Contains the mergeable, per-segment distinct state.
+///
+/// `Exact` keeps every observed value and yields an exact cardinality; `Approx` keeps a
+/// HyperLogLog sketch of bounded size and yields an estimate. Both variants stay associative
+/// under [`IntermediateDistinct::merge_fruits`] so they survive the distributed merge path.
+///
+/// "Exact" is exact for numeric fields, whose values are stored verbatim. For `Str`/`Bytes`
+/// fields the keys are 64-bit hashes of the term bytes (see [`SegmentDistinctCollector::collect_block_str`]),
+/// so two distinct terms that collide in the hash collapse into one key — the count can
+/// therefore undercount and `distinct_values` can drop a colliding term. Collisions are
+/// vanishingly rare at this width but are not impossible.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum IntermediateDistinct {
+    /// The exact set of observed values, optionally carrying a spill policy.
+    Exact(ExactDistinct),
+    /// A HyperLogLog sketch of the observed values.
+    Approx(HyperLogLog),
+}
+
+impl Default for IntermediateDistinct {
+    fn default() -> Self {
+        IntermediateDistinct::Exact(ExactDistinct::default())
+    }
 }
 
-/// Contains mergeable version of average data.
+/// The exact side of a distinct fruit.
+///
+/// When `spill` is set, the set folds into a HyperLogLog sketch as soon as it grows past the
+/// configured threshold, so a low-cardinality grouping stays exact while a large one degrades
+/// to bounded memory.
 #[derive(Default, Clone, Debug, PartialEq, Serialize, Deserialize)]
-pub struct IntermediateDistinct {
+pub struct ExactDistinct {
     pub(crate) terms: BTreeSet<u64>,
-    pub(crate) term_count: u64,
+    /// For `Str`/`Bytes` fields the keys in `terms` are stable hashes of the term bytes; this
+    /// retains the resolved bytes for each key so the `distinct_values` path can return the
+    /// actual terms rather than their hashes. Empty for numeric fields.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub(crate) term_bytes: BTreeMap<u64, Vec<u8>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    spill: Option<SpillPolicy>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+struct SpillPolicy {
+    precision: u8,
+    threshold: usize,
+}
+
+impl ExactDistinct {
+    #[inline]
+    fn should_spill(&self) -> bool {
+        matches!(self.spill, Some(policy) if self.terms.len() > policy.threshold)
+    }
+
+    /// Fold the accumulated exact set into a fresh sketch. Callers spill only when a policy is
+    /// present, so the precision is always known here.
+    fn to_sketch(&self) -> HyperLogLog {
+        let precision = self.spill.map(|policy| policy.precision).unwrap_or(14);
+        let mut hll = HyperLogLog::new(precision);
+        for &val in &self.terms {
+            hll.collect(val);
+        }
+        hll
+    }
 }
 
 impl IntermediateDistinct {
@@ -88,23 +361,254 @@ impl IntermediateDistinct {
         collector.data
     }
 
-    /// Merge average data into this instance.
+    /// Merge another distinct fruit into this instance.
+    ///
+    /// A sketch is absorbing: mixing an exact side with an approximate side promotes the exact
+    /// values into the sketch. Two exact sides stay exact unless the combined set crosses the
+    /// spill threshold, in which case they fold into a sketch together.
     pub fn merge_fruits(&mut self, other: IntermediateDistinct) {
-        self.terms.extend(other.terms);
-        self.term_count += other.term_count;
+        match (&mut *self, other) {
+            (IntermediateDistinct::Exact(a), IntermediateDistinct::Exact(b)) => {
+                a.terms.extend(b.terms);
+                a.term_bytes.extend(b.term_bytes);
+                // Preserve a spill policy contributed by either side.
+                if a.spill.is_none() {
+                    a.spill = b.spill;
+                }
+                if a.should_spill() {
+                    let sketch = a.to_sketch();
+                    *self = IntermediateDistinct::Approx(sketch);
+                }
+            }
+            (IntermediateDistinct::Approx(a), IntermediateDistinct::Approx(b)) => {
+                a.merge(&b);
+            }
+            (IntermediateDistinct::Approx(a), IntermediateDistinct::Exact(b)) => {
+                for val in b.terms {
+                    a.collect(val);
+                }
+            }
+            (this, IntermediateDistinct::Approx(mut b)) => {
+                if let IntermediateDistinct::Exact(a) = this {
+                    for &val in a.terms.iter() {
+                        b.collect(val);
+                    }
+                }
+                *this = IntermediateDistinct::Approx(b);
+            }
+        }
     }
     /// compute final result
     pub fn finalize(&self) -> Option<f64> {
-        if self.term_count == 0 {
-            None
-        } else {
-            Some(self.term_count as f64)
+        match self {
+            IntermediateDistinct::Exact(exact) => {
+                if exact.terms.is_empty() {
+                    None
+                } else {
+                    Some(exact.terms.len() as f64)
+                }
+            }
+            IntermediateDistinct::Approx(hll) => {
+                let estimate = hll.estimate();
+                if estimate == 0.0 {
+                    None
+                } else {
+                    Some(estimate)
+                }
+            }
+        }
+    }
+    /// Produce the distinct values themselves as a JSON array of typed values.
+    ///
+    /// Only the exact path retains values; a sketch keeps none, so an approximate fruit yields
+    /// an empty array. `size` caps the result and `order` selects ascending or descending order.
+    ///
+    /// For numeric fields the keys are the monotonic `u64` representation, so the `BTreeSet`
+    /// order already matches value order. For `Str`/`Bytes` fields the keys are hashes, so the
+    /// values are ordered by their resolved term bytes to honor the `"order":"asc"` contract
+    /// lexicographically rather than by an arbitrary hash order.
+    pub fn finalize_values(
+        &self,
+        field_type: Type,
+        size: Option<usize>,
+        order: DistinctValuesOrder,
+    ) -> Vec<serde_json::Value> {
+        let exact = match self {
+            IntermediateDistinct::Exact(exact) => exact,
+            IntermediateDistinct::Approx(_) => return Vec::new(),
+        };
+        // Keys in ascending value order: numeric keys are monotonic so the set order suffices;
+        // text keys are hashes, so sort by the resolved bytes instead.
+        let mut keys: Vec<u64> = exact.terms.iter().copied().collect();
+        if matches!(field_type, Type::Str | Type::Bytes) {
+            keys.sort_by(|a, b| {
+                let bytes_a = exact.term_bytes.get(a).map(Vec::as_slice).unwrap_or(&[]);
+                let bytes_b = exact.term_bytes.get(b).map(Vec::as_slice).unwrap_or(&[]);
+                bytes_a.cmp(bytes_b)
+            });
+        }
+        let cap = size.unwrap_or(keys.len());
+        let mapped = |val: &u64| map_term_to_json(field_type, *val, &exact.term_bytes);
+        match order {
+            DistinctValuesOrder::Asc => keys.iter().take(cap).map(mapped).collect(),
+            DistinctValuesOrder::Desc => keys.iter().rev().take(cap).map(mapped).collect(),
+        }
+    }
+    #[inline]
+    fn collect(&mut self, val: u64) {
+        match self {
+            IntermediateDistinct::Exact(exact) => {
+                exact.terms.insert(val);
+                if exact.should_spill() {
+                    let sketch = exact.to_sketch();
+                    *self = IntermediateDistinct::Approx(sketch);
+                }
+            }
+            IntermediateDistinct::Approx(hll) => hll.collect(val),
+        }
+    }
+    /// Collect a resolved term (for `Str`/`Bytes` fields) by its stable byte hash, retaining the
+    /// bytes so `finalize_values` can return the actual term.
+    #[inline]
+    fn collect_bytes(&mut self, bytes: &[u8]) {
+        let key = stable_hash_bytes(bytes);
+        self.collect(key);
+        // `collect` may have spilled into a sketch; only the exact path retains values.
+        if let IntermediateDistinct::Exact(exact) = self {
+            exact
+                .term_bytes
+                .entry(key)
+                .or_insert_with(|| bytes.to_vec());
+        }
+    }
+}
+
+/// Map a distinct key back to a typed JSON value according to the field type.
+///
+/// For `Str`/`Bytes` fields the key is a hash, so the resolved bytes retained in `term_bytes`
+/// are used; `Str` is emitted as a JSON string and `Bytes` as a JSON array of byte values.
+fn map_term_to_json(
+    field_type: Type,
+    val: u64,
+    term_bytes: &BTreeMap<u64, Vec<u8>>,
+) -> serde_json::Value {
+    match field_type {
+        Type::I64 => serde_json::Value::from(i64::from_u64(val)),
+        Type::F64 => serde_json::Value::from(f64::from_u64(val)),
+        Type::Bool => serde_json::Value::from(val == 1),
+        Type::Str => match term_bytes.get(&val) {
+            Some(bytes) => serde_json::Value::from(String::from_utf8_lossy(bytes).into_owned()),
+            None => serde_json::Value::Null,
+        },
+        Type::Bytes => match term_bytes.get(&val) {
+            Some(bytes) => serde_json::Value::from(bytes.clone()),
+            None => serde_json::Value::Null,
+        },
+        // `U64` and `Date` (timestamp) are surfaced as their underlying representation.
+        _ => serde_json::Value::from(val),
+    }
+}
+
+/// A fixed-size HyperLogLog sketch over `u64` values.
+///
+/// The register array is stored inline so the `Serialize`/`Deserialize` derives keep
+/// intermediate results compact across the distributed merge path.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct HyperLogLog {
+    precision: u8,
+    registers: Vec<u8>,
+}
+
+impl HyperLogLog {
+    /// Create an empty sketch with `m = 1 << precision` one-byte registers.
+    ///
+    /// `precision` is clamped to `4..=16`, the range over which the bias corrections below are
+    /// well behaved (p = 14 → 16384 registers is the usual choice).
+    pub fn new(precision: u8) -> Self {
+        let precision = precision.clamp(4, 16);
+        HyperLogLog {
+            precision,
+            registers: vec![0u8; 1usize << precision],
         }
     }
+
     #[inline]
     fn collect(&mut self, val: u64) {
-        if self.terms.insert(val) {
-            self.term_count += 1;
+        let p = self.precision as u32;
+        let hash = stable_hash_u64(val);
+        // Top `p` bits select the register.
+        let j = (hash >> (64 - p)) as usize;
+        // `rho` is the position of the leftmost set bit in the remaining `64 - p` bits, + 1.
+        let remaining = (hash << p) | (1u64 << (p - 1));
+        let rho = remaining.leading_zeros() as u8 + 1;
+        if rho > self.registers[j] {
+            self.registers[j] = rho;
         }
     }
+
+    /// Element-wise max of the two register arrays; associative and order independent.
+    ///
+    /// All sketches within one aggregation are built from the same `precision`, so a mismatch
+    /// should never reach here. Guard against it anyway: merging arrays of different lengths
+    /// would silently truncate through `zip` and corrupt the estimate, so a mismatched `other`
+    /// is skipped rather than partially merged.
+    fn merge(&mut self, other: &HyperLogLog) {
+        debug_assert_eq!(self.precision, other.precision);
+        if self.precision != other.precision {
+            return;
+        }
+        for (reg, &other_reg) in self.registers.iter_mut().zip(other.registers.iter()) {
+            if other_reg > *reg {
+                *reg = other_reg;
+            }
+        }
+    }
+
+    /// Estimate the cardinality with the standard small- and large-range corrections.
+    fn estimate(&self) -> f64 {
+        let m = self.registers.len() as f64;
+        let alpha_m = 0.7213 / (1.0 + 1.079 / m);
+        let mut sum = 0.0f64;
+        let mut zeros = 0u64;
+        for &reg in &self.registers {
+            sum += 2.0f64.powi(-(reg as i32));
+            if reg == 0 {
+                zeros += 1;
+            }
+        }
+        let raw = alpha_m * m * m / sum;
+        if raw <= 2.5 * m && zeros > 0 {
+            // Small-range (linear counting) correction.
+            m * (m / zeros as f64).ln()
+        } else if raw > (1u64 << 32) as f64 / 30.0 {
+            // Large-range correction near 2^32.
+            let two_pow_32 = (1u64 << 32) as f64;
+            -two_pow_32 * (1.0 - raw / two_pow_32).ln()
+        } else {
+            raw
+        }
+    }
+}
+
+/// A stable 64-bit mix hash (splitmix64 finalizer) so register assignment is deterministic
+/// across segments and across serialized merges.
+#[inline]
+fn stable_hash_u64(val: u64) -> u64 {
+    let mut z = val.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// A stable 64-bit hash (FNV-1a) of term bytes, used to turn per-segment term ordinals into
+/// globally comparable distinct keys. Being a hash, it is subject to rare collisions that make
+/// the text distinct path undercount (see [`SegmentDistinctCollector::collect_block_str`]).
+#[inline]
+fn stable_hash_bytes(bytes: &[u8]) -> u64 {
+    let mut hash = 0xCBF2_9CE4_8422_2325u64;
+    for &byte in bytes {
+        hash ^= byte as u64;
+        hash = hash.wrapping_mul(0x0000_0100_0000_01B3);
+    }
+    hash
 }